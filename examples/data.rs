@@ -1,7 +1,7 @@
 // cargo test --example data -F data
 use clojure_reader::{
   data::Datum,
-  edn,
+  edn::{self, TagError},
   parse::{Node, NodeKind},
 };
 use core::fmt;
@@ -21,6 +21,8 @@ impl fmt::Display for Person {
 fn main() {
   let mut reader = edn::Reader::new();
 
+  reader.add_writer::<Person>("person", |person, f| write!(f, "[{} {}]", person.name, person.age));
+
   reader.add_reader("person", |node| {
     // Expect a vector of two elements - a symbol (name) and an integer (age)
     if let NodeKind::Vector(nodes, _) = node.kind
@@ -36,12 +38,19 @@ fn main() {
         Datum::new(person),
       ))
     } else {
-      panic!("unexpected")
+      Err(edn::ReadError::Tag(TagError {
+        message: "expected [symbol int]".into(),
+        position: node.span.start_position(),
+      }))
     }
   });
 
   let source = r#" #person [John 34] "#;
-  let edn::Edn::Data(data) = reader.read_string(source).unwrap() else { panic!("unexpected") };
+  let edn = reader.read_string(source).unwrap();
+
+  assert_eq!(format!("{}", reader.display(&edn)), "#person [John 34]");
+
+  let edn::Edn::Data(data) = edn else { panic!("unexpected") };
   let person: Person = *data.downcast().unwrap();
 
   assert_eq!(person.name, "John");
@@ -0,0 +1,43 @@
+// cargo test --example partial_data -F data
+use clojure_reader::{data::Datum, edn};
+use core::{cmp::Ordering, fmt};
+
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+struct GeoPoint {
+  lat: f64,
+  lon: f64,
+}
+
+impl fmt::Display for GeoPoint {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "GeoPoint({}, {})", self.lat, self.lon)
+  }
+}
+
+fn main() {
+  let mut reader = edn::Reader::new();
+
+  reader.add_writer::<GeoPoint>("geo", |p, f| write!(f, "[{} {}]", p.lat, p.lon));
+
+  // requires only `Debug`, `Display`, `Clone`, `PartialEq` & `PartialOrd` - `GeoPoint` holds an
+  // `f64` so it can't satisfy `DataTrait`'s `Eq` + `Ord` + `Hash` bound
+  let here = Datum::new_partial(GeoPoint { lat: 51.5, lon: -0.1 });
+  let there = Datum::new_partial(GeoPoint { lat: 48.9, lon: 2.3 });
+
+  // `Ord::cmp` never panics, even across two points that aren't comparable (NaN), falling back
+  // to `Ordering::Equal`
+  let unorderable = Datum::new_partial(GeoPoint { lat: f64::NAN, lon: 0.0 });
+  assert_eq!(here.cmp(&there), Ordering::Greater);
+  assert_eq!(unorderable.cmp(&unorderable), Ordering::Equal);
+
+  let edn = edn::Edn::Data(here.clone());
+  assert_eq!(format!("{}", reader.display(&edn)), "#geo [51.5 -0.1]");
+
+  let point: GeoPoint = *here.downcast().unwrap();
+  assert_eq!(point, GeoPoint { lat: 51.5, lon: -0.1 });
+}
+
+#[test]
+fn run() {
+  main();
+}
@@ -0,0 +1,36 @@
+// cargo test --example position -F data
+use clojure_reader::{
+  edn::{self, Edn, ReadError, TagError},
+  parse::{NodeKind, Position},
+};
+use std::collections::BTreeMap;
+
+fn main() {
+  let mut reader = edn::Reader::new();
+
+  reader.add_reader("person", |node| {
+    Err(ReadError::Tag(TagError { message: "expected [symbol int]".into(), position: node.span.start_position() }))
+  });
+
+  // the malformed tag sits on the second line, indented by 2 columns
+  let source = "[0\n  #person {:not :a-vector}]";
+  let err = reader.read_string(source).unwrap_err();
+  assert_eq!(format!("{err}"), "expected [symbol int] at line 2, column 11");
+
+  // `Position` is `Clone + Eq + Ord + Hash`, so it can key a map of source locations, letting
+  // tooling look up which node sits at a given line/column
+  reader.add_reader("coords", |node| {
+    let NodeKind::Vector(items, _) = node.kind else { panic!("unexpected") };
+    let by_position: BTreeMap<Position, NodeKind> =
+      items.into_iter().map(|item| (item.span.start_position(), item.kind)).collect();
+    assert_eq!(by_position.len(), 3);
+    assert_eq!(by_position[&Position { line: 1, column: 10 }], NodeKind::Int(1));
+    Ok(Edn::Nil)
+  });
+  reader.read_string("#coords [1 2 3]").unwrap();
+}
+
+#[test]
+fn run() {
+  main();
+}
@@ -0,0 +1,23 @@
+// cargo test --example std_tags -F std-tags
+use clojure_reader::edn;
+
+fn main() {
+  let reader = edn::Reader::new();
+
+  let edn = reader.read_string(r#" #uuid "f81d4fae-7dec-11d0-a765-00a0c91e6bf6" "#).unwrap();
+  assert_eq!(format!("{}", reader.display(&edn)), r#"#uuid "f81d4fae-7dec-11d0-a765-00a0c91e6bf6""#);
+
+  let edn = reader.read_string(r#" #inst "1985-04-12T23:20:50.520Z" "#).unwrap();
+  assert_eq!(format!("{}", reader.display(&edn)), r#"#inst "1985-04-12T23:20:50.520Z""#);
+
+  // a malformed UUID errors cleanly instead of panicking
+  assert!(reader.read_string(r#" #uuid "not-a-uuid" "#).is_err());
+
+  // a nonexistent calendar date errors cleanly instead of silently rolling forward
+  assert!(reader.read_string(r#" #inst "2023-02-30T00:00:00Z" "#).is_err());
+}
+
+#[test]
+fn run() {
+  main();
+}
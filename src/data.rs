@@ -2,6 +2,10 @@
 //!
 //! ## Implementations
 //! -  [`DataTrait`] blanket-implemented for all types that implement [`Debug`], [`Display`], [`Clone`], [`PartialEq`], [`Eq`], [`PartialOrd`], [`Ord`] & [`Hash`]
+//! -  [`PartialDataTrait`] blanket-implemented for types that only implement [`Debug`], [`Display`], [`Clone`], [`PartialEq`] & [`PartialOrd`] (e.g. anything holding an `f64`), stored via [`Datum::new_partial`]
+//! -  [`Datum::type_id`] & [`Datum::as_dyn_any`] let [`Reader`][crate::edn::Reader] look up a
+//!    writer registered with [`add_writer`][crate::edn::Reader::add_writer] to print a tagged
+//!    [`Datum`] back out as EDN
 
 use alloc::boxed::Box;
 use core::{
@@ -10,7 +14,23 @@ use core::{
   hash::{Hash, Hasher},
 };
 
-/// Dyn-compatible trait to store dynamically-typed values present in [`Edn::Data`][Data]
+/// Hasher that wraps reference `&'a mut dyn Hasher`
+struct Adapter<'a> {
+  state: &'a mut dyn Hasher,
+}
+
+impl Hasher for Adapter<'_> {
+  fn finish(&self) -> u64 {
+    self.state.finish()
+  }
+  fn write(&mut self, bytes: &[u8]) {
+    self.state.write(bytes);
+  }
+}
+
+/// Dyn-compatible trait to store totally-ordered dynamically-typed values present in
+/// [`Edn::Data`][Data]. Types that are only partially ordered (e.g. anything holding an `f64`)
+/// can't implement this; see [`PartialDataTrait`] instead.
 ///
 /// [Data]: crate::edn::Edn::Data
 pub trait DataTrait: Any + fmt::Debug + fmt::Display {
@@ -21,6 +41,23 @@ pub trait DataTrait: Any + fmt::Debug + fmt::Display {
   fn partial_cmp_(&self, other: &dyn DataTrait) -> Option<core::cmp::Ordering> {
     Some(self.cmp_(other))
   }
+
+  /// The EDN tag this value should be printed back out under, e.g. `Some("person")` to have a
+  /// `Datum` wrapping it print as `#person ...`. Returns `None` by default, meaning the value
+  /// prints untagged via [`write_edn`][Self::write_edn].
+  ///
+  /// Types stored in a [`Reader`][crate::edn::Reader] are usually better served by registering a
+  /// writer with [`Reader::add_writer`][crate::edn::Reader::add_writer] instead, since it can
+  /// write a tag without requiring a custom [`DataTrait`] impl.
+  fn edn_tag(&self) -> Option<&str> {
+    None
+  }
+
+  /// Writes this value back out as valid EDN. Falls through to [`Display`][fmt::Display] by
+  /// default, which is only correct EDN if the type's `Display` impl already produces it.
+  fn write_edn(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt::Display::fmt(self, f)
+  }
 }
 
 impl<T> DataTrait for T
@@ -48,75 +85,166 @@ where
   }
 
   fn hash_(&self, state: &mut dyn Hasher) {
-    /// Hasher that wraps reference `&'a mut dyn Hasher`
-    struct Adapter<'a> {
-      state: &'a mut dyn Hasher,
-    }
-    impl Hasher for Adapter<'_> {
-      fn finish(&self) -> u64 {
-        self.state.finish()
-      }
-      fn write(&mut self, bytes: &[u8]) {
-        self.state.write(bytes);
-      }
-    }
-
     self.hash(&mut Adapter { state });
   }
 }
 
+/// Dyn-compatible trait to store dynamically-typed values in [`Edn::Data`][Data] that only have
+/// a partial order, e.g. anything holding an `f64`. Stored via [`Datum::new_partial`]; prefer
+/// [`DataTrait`] (stored via [`Datum::new`]) when `T` is totally ordered, since it sorts & hashes
+/// properly instead of falling back to [`Ordering::Equal`][core::cmp::Ordering::Equal] and a
+/// fixed hash.
+///
+/// [Data]: crate::edn::Edn::Data
+pub trait PartialDataTrait: Any + fmt::Debug + fmt::Display {
+  fn clone_(&self) -> Box<dyn PartialDataTrait>;
+  fn eq_(&self, other: &dyn PartialDataTrait) -> bool;
+  fn partial_cmp_(&self, other: &dyn PartialDataTrait) -> Option<core::cmp::Ordering>;
+
+  /// Hashes a fixed discriminant rather than the value, since `T` isn't required to implement
+  /// `Hash`. Only [`eq_`][Self::eq_] distinguishes unequal values stored this way, so they all
+  /// land in the same hash bucket.
+  fn hash_(&self, state: &mut dyn Hasher) {
+    "clojure_reader::data::PartialDataTrait".hash(&mut Adapter { state });
+  }
+
+  /// See [`DataTrait::edn_tag`]
+  fn edn_tag(&self) -> Option<&str> {
+    None
+  }
+
+  /// See [`DataTrait::write_edn`]
+  fn write_edn(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt::Display::fmt(self, f)
+  }
+}
+
+impl<T> PartialDataTrait for T
+where
+  T: fmt::Debug + fmt::Display + Clone + PartialEq + PartialOrd + 'static,
+{
+  fn clone_(&self) -> Box<dyn PartialDataTrait> {
+    Box::new(self.clone())
+  }
+
+  fn eq_(&self, other: &dyn PartialDataTrait) -> bool {
+    let other: &dyn Any = other;
+    other.downcast_ref().is_some_and(|other| self.eq(other))
+  }
+
+  fn partial_cmp_(&self, other: &dyn PartialDataTrait) -> Option<core::cmp::Ordering> {
+    let other: &dyn Any = other;
+    other.downcast_ref().and_then(|other| self.partial_cmp(other))
+  }
+}
+
+#[derive(Debug)]
+enum Inner {
+  /// Backed by a totally-ordered [`DataTrait`] value
+  Total(Box<dyn DataTrait>),
+  /// Backed by a only-partially-ordered [`PartialDataTrait`] value
+  Partial(Box<dyn PartialDataTrait>),
+}
+
 /// Pointer to a dynamically-typed value, used in [`Edn::Data`](crate::edn::Edn::Data)
 #[derive(Debug)]
-pub struct Datum(Box<dyn DataTrait>);
+pub struct Datum(Inner);
 
 impl fmt::Display for Datum {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-    self.0.fmt(f)
+    match &self.0 {
+      Inner::Total(d) => {
+        if let Some(tag) = d.edn_tag() {
+          write!(f, "#{tag} ")?;
+        }
+        d.write_edn(f)
+      }
+      Inner::Partial(d) => {
+        if let Some(tag) = d.edn_tag() {
+          write!(f, "#{tag} ")?;
+        }
+        d.write_edn(f)
+      }
+    }
   }
 }
 
 impl Clone for Datum {
   fn clone(&self) -> Self {
-    Self(self.0.clone_())
+    Self(match &self.0 {
+      Inner::Total(d) => Inner::Total(d.clone_()),
+      Inner::Partial(d) => Inner::Partial(d.clone_()),
+    })
   }
 }
 
 impl PartialEq for Datum {
   fn eq(&self, other: &Self) -> bool {
-    Any::type_id(&self.0) == Any::type_id(&other.0) && self.0.eq_(&*other.0)
+    match (&self.0, &other.0) {
+      (Inner::Total(a), Inner::Total(b)) => Any::type_id(&**a) == Any::type_id(&**b) && a.eq_(&**b),
+      (Inner::Partial(a), Inner::Partial(b)) => Any::type_id(&**a) == Any::type_id(&**b) && a.eq_(&**b),
+      (Inner::Total(_), Inner::Partial(_)) | (Inner::Partial(_), Inner::Total(_)) => false,
+    }
   }
 }
 
+/// Not reflexive for an `Inner::Partial`-backed `Datum` whose underlying value isn't reflexively
+/// equal to itself (e.g. anything holding `f64::NAN`, mirroring why `f64` itself has no `Eq`
+/// impl) — `eq_` faithfully reports that inequality rather than masking it. Stick to
+/// [`Datum::new`]/`DataTrait` (i.e. avoid NaN-bearing types) if a `Datum` needs to go in a
+/// `HashSet`/`HashMap` and be found again via `.contains()`/`.get()`.
 impl Eq for Datum {}
 
 impl PartialOrd for Datum {
-  #[expect(clippy::non_canonical_partial_ord_impl)]
   fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
-    self.0.partial_cmp_(&*other.0)
+    Some(self.cmp(other))
   }
 }
 
 impl Ord for Datum {
+  /// Never panics, even when the underlying value is only partially ordered: falls back to
+  /// [`Ordering::Equal`][core::cmp::Ordering::Equal] where no order is defined.
   fn cmp(&self, other: &Self) -> core::cmp::Ordering {
-    self.0.cmp_(&*other.0)
+    use core::cmp::Ordering;
+    match (&self.0, &other.0) {
+      (Inner::Total(a), Inner::Total(b)) => a.partial_cmp_(&**b).unwrap_or(Ordering::Equal),
+      (Inner::Partial(a), Inner::Partial(b)) => a.partial_cmp_(&**b).unwrap_or(Ordering::Equal),
+      (Inner::Total(_), Inner::Partial(_)) => Ordering::Less,
+      (Inner::Partial(_), Inner::Total(_)) => Ordering::Greater,
+    }
   }
 }
 
 impl Hash for Datum {
   fn hash<H: Hasher>(&self, state: &mut H) {
-    self.0.hash_(state);
+    match &self.0 {
+      Inner::Total(d) => d.hash_(state),
+      Inner::Partial(d) => d.hash_(state),
+    }
   }
 }
 
 impl Datum {
   /// Requires that `T` implement `Debug`, `Display`, `Clone`, `PartialEq`, `Eq`, `PartialOrd`, `Ord` & `Hash`
   pub fn new<T: DataTrait>(t: T) -> Self {
-    Self(Box::new(t))
+    Self(Inner::Total(Box::new(t)))
   }
 
   /// Requires that `T` implement `Debug`, `Display`, `Clone`, `PartialEq`, `Eq`, `PartialOrd`, `Ord` & `Hash`
   pub fn from_boxed<T: DataTrait>(t: Box<T>) -> Self {
-    Self(t)
+    Self(Inner::Total(t))
+  }
+
+  /// Stores a `T` that is only partially ordered (e.g. holds an `f64`), at the cost of comparing
+  /// and hashing as described on [`PartialDataTrait`]. Requires that `T` implement `Debug`,
+  /// `Display`, `Clone`, `PartialEq` & `PartialOrd`.
+  pub fn new_partial<T: PartialDataTrait>(t: T) -> Self {
+    Self(Inner::Partial(Box::new(t)))
+  }
+
+  /// Requires that `T` implement `Debug`, `Display`, `Clone`, `PartialEq` & `PartialOrd`
+  pub fn from_boxed_partial<T: PartialDataTrait>(t: Box<T>) -> Self {
+    Self(Inner::Partial(t))
   }
 
   /// Downcast the datum to an expected concrete-type `T`
@@ -124,8 +252,34 @@ impl Datum {
   /// # Errors
   ///
   /// Returns the original data-pointer `Box<dyn Any>` in case the concrete type didn't correspond
-  pub fn downcast<T: DataTrait + 'static>(self) -> Result<Box<T>, Box<dyn Any>> {
-    let o: Box<dyn Any> = self.0;
-    o.downcast()
+  pub fn downcast<T: 'static>(self) -> Result<Box<T>, Box<dyn Any>> {
+    match self.0 {
+      Inner::Total(d) => {
+        let o: Box<dyn Any> = d;
+        o.downcast()
+      }
+      Inner::Partial(d) => {
+        let o: Box<dyn Any> = d;
+        o.downcast()
+      }
+    }
+  }
+
+  /// The [`TypeId`][core::any::TypeId] of the concrete type erased inside this datum, used by
+  /// [`Reader`][crate::edn::Reader] to look up a writer registered with
+  /// [`add_writer`][crate::edn::Reader::add_writer]
+  pub fn type_id(&self) -> core::any::TypeId {
+    match &self.0 {
+      Inner::Total(d) => Any::type_id(&**d),
+      Inner::Partial(d) => Any::type_id(&**d),
+    }
+  }
+
+  /// Borrows the erased value as [`dyn Any`], for downcasting without consuming the datum
+  pub fn as_dyn_any(&self) -> &dyn Any {
+    match &self.0 {
+      Inner::Total(d) => &**d,
+      Inner::Partial(d) => &**d,
+    }
   }
 }
@@ -0,0 +1,274 @@
+//! The [`Edn`] value type and the [`Reader`] that turns EDN source text into it
+//!
+//! ## Implementations
+//! -  [`Reader::add_reader`] lets you install a tagged-element reader, turning `#tag ...` into an
+//!    [`Edn::Data`] (requires the `data` feature)
+
+#[cfg(feature = "data")]
+use crate::data::Datum;
+use crate::parse::{self, Node, NodeKind, ParseError, Position};
+use alloc::{string::String, vec::Vec};
+#[cfg(feature = "data")]
+use alloc::{boxed::Box, collections::BTreeMap};
+use core::fmt;
+#[cfg(feature = "data")]
+use core::any::{Any, TypeId};
+
+/// A parsed EDN value
+#[derive(Debug, Clone, PartialEq)]
+pub enum Edn {
+  Nil,
+  Bool(bool),
+  Int(i64),
+  Float(f64),
+  String(String),
+  Symbol(String),
+  Keyword(String),
+  Char(char),
+  List(Vec<Edn>),
+  Vector(Vec<Edn>),
+  Map(Vec<(Edn, Edn)>),
+  Set(Vec<Edn>),
+  /// A value produced by a tagged-element reader installed with [`Reader::add_reader`]
+  #[cfg(feature = "data")]
+  Data(Datum),
+}
+
+impl fmt::Display for Edn {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Nil => write!(f, "nil"),
+      Self::Bool(b) => write!(f, "{b}"),
+      Self::Int(i) => write!(f, "{i}"),
+      Self::Float(n) => write!(f, "{n}"),
+      Self::String(s) => write!(f, "{s:?}"),
+      Self::Symbol(s) => write!(f, "{s}"),
+      Self::Keyword(s) => write!(f, ":{s}"),
+      Self::Char(c) => write!(f, "\\{c}"),
+      Self::List(items) => write_seq(f, "(", items, ")"),
+      Self::Vector(items) => write_seq(f, "[", items, "]"),
+      Self::Set(items) => write_seq(f, "#{", items, "}"),
+      Self::Map(pairs) => {
+        write!(f, "{{")?;
+        for (i, (k, v)) in pairs.iter().enumerate() {
+          if i > 0 {
+            write!(f, " ")?;
+          }
+          write!(f, "{k} {v}")?;
+        }
+        write!(f, "}}")
+      }
+      #[cfg(feature = "data")]
+      Self::Data(datum) => fmt::Display::fmt(datum, f),
+    }
+  }
+}
+
+fn write_seq(f: &mut fmt::Formatter<'_>, open: &str, items: &[Edn], close: &str) -> fmt::Result {
+  write!(f, "{open}")?;
+  for (i, item) in items.iter().enumerate() {
+    if i > 0 {
+      write!(f, " ")?;
+    }
+    write!(f, "{item}")?;
+  }
+  write!(f, "{close}")
+}
+
+/// An error produced while reading EDN source text, either from malformed syntax or from a
+/// tagged-element reader installed with [`Reader::add_reader`]
+#[derive(Debug)]
+pub enum ReadError {
+  Parse(ParseError),
+  Tag(TagError),
+}
+
+impl fmt::Display for ReadError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Parse(err) => write!(f, "{err}"),
+      Self::Tag(err) => write!(f, "{err}"),
+    }
+  }
+}
+
+/// An error returned by a tagged-element reader installed with [`Reader::add_reader`] when the
+/// [`Node`] it was handed doesn't have the expected shape, e.g. `"expected [symbol int] at line
+/// 1, column 2"` rather than a panic
+#[derive(Debug)]
+pub struct TagError {
+  pub message: String,
+  pub position: Position,
+}
+
+impl fmt::Display for TagError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{} at {}", self.message, self.position)
+  }
+}
+
+impl From<ParseError> for ReadError {
+  fn from(err: ParseError) -> Self {
+    Self::Parse(err)
+  }
+}
+
+#[cfg(feature = "data")]
+type TagReader = Box<dyn Fn(Node) -> Result<Edn, ReadError>>;
+#[cfg(feature = "data")]
+type TagWriter = Box<dyn Fn(&dyn Any, &mut fmt::Formatter<'_>) -> fmt::Result>;
+
+/// Reads EDN source text into [`Edn`] values, dispatching tagged elements (with the `data`
+/// feature) to readers installed with [`add_reader`][Reader::add_reader], and writes them back
+/// out with [`display`][Reader::display], dispatching to writers installed with
+/// [`add_writer`][Reader::add_writer]
+#[derive(Default)]
+pub struct Reader {
+  #[cfg(feature = "data")]
+  readers: BTreeMap<String, TagReader>,
+  #[cfg(feature = "data")]
+  writers: BTreeMap<TypeId, (String, TagWriter)>,
+}
+
+impl Reader {
+  pub fn new() -> Self {
+    #[cfg_attr(not(feature = "std-tags"), allow(unused_mut))]
+    let mut reader = Self::default();
+    #[cfg(feature = "std-tags")]
+    crate::std_tags::install(&mut reader);
+    reader
+  }
+
+  /// Installs a reader for tagged elements `#tag ...`, converting the parsed [`Node`] that
+  /// follows the tag into an [`Edn::Data`]
+  #[cfg(feature = "data")]
+  pub fn add_reader(&mut self, tag: &str, f: impl Fn(Node) -> Result<Edn, ReadError> + 'static) {
+    self.readers.insert(tag.into(), Box::new(f));
+  }
+
+  /// Installs a writer that prints a `Datum` wrapping a `T` back out as a tagged element
+  /// `#tag ...`, the counterpart to [`add_reader`][Self::add_reader]. Only takes effect when
+  /// formatting through [`display`][Self::display]; a bare `Datum` still falls back to
+  /// [`DataTrait::write_edn`][crate::data::DataTrait::write_edn]/
+  /// [`PartialDataTrait::write_edn`][crate::data::PartialDataTrait::write_edn]. Bound on `Any`
+  /// rather than `DataTrait` so it can also be registered for a type only stored via
+  /// [`Datum::new_partial`][crate::data::Datum::new_partial].
+  #[cfg(feature = "data")]
+  pub fn add_writer<T: Any>(&mut self, tag: &str, write: impl Fn(&T, &mut fmt::Formatter<'_>) -> fmt::Result + 'static) {
+    self.writers.insert(
+      TypeId::of::<T>(),
+      (
+        tag.into(),
+        Box::new(move |any, f| write(any.downcast_ref::<T>().expect("writer registered for mismatched TypeId"), f)),
+      ),
+    );
+  }
+
+  /// Wraps `edn` so that formatting it writes any [`Edn::Data`] it contains back out using the
+  /// writers installed with [`add_writer`][Self::add_writer], falling back to the `Datum`'s own
+  /// [`Display`][fmt::Display] for types with no writer registered
+  #[cfg(feature = "data")]
+  pub fn display<'r>(&'r self, edn: &'r Edn) -> EdnDisplay<'r> {
+    EdnDisplay { reader: self, edn }
+  }
+
+  #[cfg(feature = "data")]
+  fn fmt_edn(&self, edn: &Edn, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match edn {
+      Edn::Data(datum) => match self.writers.get(&datum.type_id()) {
+        Some((tag, write)) => {
+          write!(f, "#{tag} ")?;
+          write(datum.as_dyn_any(), f)
+        }
+        None => fmt::Display::fmt(datum, f),
+      },
+      Edn::List(items) => self.fmt_seq(f, "(", items, ")"),
+      Edn::Vector(items) => self.fmt_seq(f, "[", items, "]"),
+      Edn::Set(items) => self.fmt_seq(f, "#{", items, "}"),
+      Edn::Map(pairs) => {
+        write!(f, "{{")?;
+        for (i, (k, v)) in pairs.iter().enumerate() {
+          if i > 0 {
+            write!(f, " ")?;
+          }
+          self.fmt_edn(k, f)?;
+          write!(f, " ")?;
+          self.fmt_edn(v, f)?;
+        }
+        write!(f, "}}")
+      }
+      other => fmt::Display::fmt(other, f),
+    }
+  }
+
+  #[cfg(feature = "data")]
+  fn fmt_seq(&self, f: &mut fmt::Formatter<'_>, open: &str, items: &[Edn], close: &str) -> fmt::Result {
+    write!(f, "{open}")?;
+    for (i, item) in items.iter().enumerate() {
+      if i > 0 {
+        write!(f, " ")?;
+      }
+      self.fmt_edn(item, f)?;
+    }
+    write!(f, "{close}")
+  }
+
+  /// Parses `source` and converts it to an [`Edn`] value, dispatching any tagged elements
+  /// through the readers installed with [`add_reader`][Self::add_reader]
+  pub fn read_string(&self, source: &str) -> Result<Edn, ReadError> {
+    let Some((node, _)) = parse::parse_one(source, 0)? else {
+      return Err(ReadError::Parse(ParseError { message: "empty input".into(), offset: 0 }));
+    };
+    self.to_edn(node)
+  }
+
+  fn to_edn(&self, node: Node) -> Result<Edn, ReadError> {
+    Ok(match node.kind {
+      NodeKind::Nil => Edn::Nil,
+      NodeKind::Bool(b) => Edn::Bool(b),
+      NodeKind::Int(i) => Edn::Int(i),
+      NodeKind::Float(n) => Edn::Float(n),
+      NodeKind::String(s) => Edn::String(s),
+      NodeKind::Symbol(s) => Edn::Symbol(s),
+      NodeKind::Keyword(s) => Edn::Keyword(s),
+      NodeKind::Char(c) => Edn::Char(c),
+      NodeKind::List(items, _meta) => Edn::List(self.to_edn_all(items)?),
+      NodeKind::Vector(items, _meta) => Edn::Vector(self.to_edn_all(items)?),
+      NodeKind::Set(items, _meta) => Edn::Set(self.to_edn_all(items)?),
+      NodeKind::Map(pairs, _meta) => {
+        let mut out = Vec::with_capacity(pairs.len());
+        for (k, v) in pairs {
+          out.push((self.to_edn(k)?, self.to_edn(v)?));
+        }
+        Edn::Map(out)
+      }
+      #[cfg(feature = "data")]
+      NodeKind::Tagged(tag, inner) => match self.readers.get(&tag) {
+        Some(reader) => reader(*inner)?,
+        None => return Err(ReadError::Parse(ParseError { message: "no reader registered for tag".into(), offset: node.span.start })),
+      },
+      #[cfg(not(feature = "data"))]
+      NodeKind::Tagged(..) => {
+        return Err(ReadError::Parse(ParseError { message: "tagged elements require the `data` feature".into(), offset: node.span.start }));
+      }
+    })
+  }
+
+  fn to_edn_all(&self, nodes: Vec<Node>) -> Result<Vec<Edn>, ReadError> {
+    nodes.into_iter().map(|n| self.to_edn(n)).collect()
+  }
+}
+
+/// Formats an [`Edn`] through its owning [`Reader`], returned by [`Reader::display`]
+#[cfg(feature = "data")]
+pub struct EdnDisplay<'r> {
+  reader: &'r Reader,
+  edn: &'r Edn,
+}
+
+#[cfg(feature = "data")]
+impl fmt::Display for EdnDisplay<'_> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    self.reader.fmt_edn(self.edn, f)
+  }
+}
@@ -0,0 +1,12 @@
+//! A small, dependency-free reader for [EDN](https://github.com/edn-format/edn)
+
+#![no_std]
+
+extern crate alloc;
+
+#[cfg(feature = "data")]
+pub mod data;
+pub mod edn;
+pub mod parse;
+#[cfg(feature = "std-tags")]
+pub mod std_tags;
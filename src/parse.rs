@@ -0,0 +1,287 @@
+//! Parsed representation of EDN source text
+//!
+//! ## Implementations
+//! -  [`Node`] pairs a [`NodeKind`] with the [`Span`] of source it was read from
+//! -  [`Span::start_position`] & [`Span::end_position`] expose the line/column of a [`Node`],
+//!    tracked by the parser as it scans, for use in reader diagnostics
+
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::fmt;
+
+/// A 1-indexed line/column position within EDN source text
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Position {
+  pub line: u32,
+  pub column: u32,
+}
+
+impl fmt::Display for Position {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "line {}, column {}", self.line, self.column)
+  }
+}
+
+/// Scans `source` up to byte-offset `offset`, counting lines & columns
+fn position_at(source: &str, offset: usize) -> Position {
+  let mut line = 1;
+  let mut column = 1;
+  for c in source[..offset].chars() {
+    if c == '\n' {
+      line += 1;
+      column = 1;
+    } else {
+      column += 1;
+    }
+  }
+  Position { line, column }
+}
+
+/// Byte-offset range of a [`Node`] within the source string it was read from, together with the
+/// line/column [`Position`] at each end
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Span {
+  pub start: usize,
+  pub end: usize,
+  start_position: Position,
+  end_position: Position,
+}
+
+impl Span {
+  /// The line/column of the first byte in this span
+  pub fn start_position(&self) -> Position {
+    self.start_position
+  }
+
+  /// The line/column just past the last byte in this span
+  pub fn end_position(&self) -> Position {
+    self.end_position
+  }
+}
+
+/// A single parsed element of EDN source, together with the [`Span`] it was read from
+#[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::manual_non_exhaustive, reason = "the hidden field is internal bookkeeping, not an API-evolution placeholder")]
+pub struct Node {
+  pub kind: NodeKind,
+  pub span: Span,
+  pub(crate) _private: (),
+}
+
+impl Node {
+  pub(crate) fn new(kind: NodeKind, span: Span) -> Self {
+    Self { kind, span, _private: () }
+  }
+}
+
+/// The shape of a single parsed EDN element
+#[derive(Debug, Clone, PartialEq)]
+pub enum NodeKind {
+  Nil,
+  Bool(bool),
+  Int(i64),
+  Float(f64),
+  String(String),
+  Symbol(String),
+  Keyword(String),
+  Char(char),
+  /// Optional metadata for the collection. This parser does not read `^` metadata reader macros
+  /// and always leaves it `None`
+  List(Vec<Node>, Option<Box<Node>>),
+  Vector(Vec<Node>, Option<Box<Node>>),
+  Map(Vec<(Node, Node)>, Option<Box<Node>>),
+  Set(Vec<Node>, Option<Box<Node>>),
+  /// A tagged element, e.g. `#person [John 34]`, before it has been handed to a registered reader
+  Tagged(String, Box<Node>),
+}
+
+/// A malformed-source error produced while scanning [`Node`]s out of an EDN string
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+  pub message: String,
+  pub offset: usize,
+}
+
+impl fmt::Display for ParseError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{} at byte offset {}", self.message, self.offset)
+  }
+}
+
+fn is_delimiter(c: char) -> bool {
+  c.is_whitespace() || matches!(c, ',' | '(' | ')' | '[' | ']' | '{' | '}' | '"' | ';')
+}
+
+fn skip_whitespace(source: &str, mut pos: usize) -> usize {
+  let bytes = source.as_bytes();
+  loop {
+    match bytes.get(pos) {
+      Some(b) if (*b as char).is_whitespace() || *b == b',' => pos += 1,
+      Some(b';') => {
+        while bytes.get(pos).is_some_and(|b| *b != b'\n') {
+          pos += 1;
+        }
+      }
+      _ => return pos,
+    }
+  }
+}
+
+fn take_token(source: &str, pos: usize) -> (&str, usize) {
+  let start = pos;
+  let mut end = pos;
+  for c in source[pos..].chars() {
+    if is_delimiter(c) || c == '#' && end != start {
+      break;
+    }
+    end += c.len_utf8();
+  }
+  (&source[start..end], end)
+}
+
+/// Parses a single [`Node`] starting at byte-offset `pos`, returning it along with the offset
+/// just past it. Returns `Ok(None)` if only trailing whitespace/comments remain.
+pub(crate) fn parse_one(source: &str, pos: usize) -> Result<Option<(Node, usize)>, ParseError> {
+  let start = skip_whitespace(source, pos);
+  let Some(c) = source[start..].chars().next() else { return Ok(None) };
+
+  macro_rules! node {
+    ($kind:expr, $end:expr) => {{
+      let end = $end;
+      let span = Span {
+        start,
+        end,
+        start_position: position_at(source, start),
+        end_position: position_at(source, end),
+      };
+      Ok(Some((Node::new($kind, span), end)))
+    }};
+  }
+
+  match c {
+    '(' => {
+      let (children, end) = parse_seq(source, start + 1, ')')?;
+      node!(NodeKind::List(children, None), end)
+    }
+    '[' => {
+      let (children, end) = parse_seq(source, start + 1, ']')?;
+      node!(NodeKind::Vector(children, None), end)
+    }
+    '{' => {
+      let (children, end) = parse_seq(source, start + 1, '}')?;
+      if children.len() % 2 != 0 {
+        return Err(ParseError { message: "map literal must have an even number of forms".into(), offset: end });
+      }
+      let mut pairs = Vec::with_capacity(children.len() / 2);
+      let mut children = children.into_iter();
+      while let (Some(k), Some(v)) = (children.next(), children.next()) {
+        pairs.push((k, v));
+      }
+      node!(NodeKind::Map(pairs, None), end)
+    }
+    '"' => {
+      let (s, end) = parse_string(source, start)?;
+      node!(NodeKind::String(s), end)
+    }
+    '\\' => {
+      let (ch, end) = parse_char(source, start)?;
+      node!(NodeKind::Char(ch), end)
+    }
+    ':' => {
+      let (tok, end) = take_token(source, start + 1);
+      node!(NodeKind::Keyword(tok.into()), end)
+    }
+    '#' => {
+      if source[start..].starts_with("#{") {
+        let (children, end) = parse_seq(source, start + 2, '}')?;
+        node!(NodeKind::Set(children, None), end)
+      } else {
+        let (tag, tag_end) = take_token(source, start + 1);
+        if tag.is_empty() {
+          return Err(ParseError { message: "expected a tag name after '#'".into(), offset: tag_end });
+        }
+        let Some((inner, end)) = parse_one(source, tag_end)? else {
+          return Err(ParseError { message: "expected a form after tag".into(), offset: tag_end });
+        };
+        node!(NodeKind::Tagged(tag.into(), Box::new(inner)), end)
+      }
+    }
+    ')' | ']' | '}' => Err(ParseError { message: "unexpected closing delimiter".into(), offset: start }),
+    _ => {
+      let (tok, end) = take_token(source, start);
+      if tok.is_empty() {
+        return Err(ParseError { message: "unexpected character".into(), offset: start });
+      }
+      match tok {
+        "nil" => node!(NodeKind::Nil, end),
+        "true" => node!(NodeKind::Bool(true), end),
+        "false" => node!(NodeKind::Bool(false), end),
+        _ => {
+          if let Ok(i) = tok.parse::<i64>() {
+            node!(NodeKind::Int(i), end)
+          } else if tok.chars().next().is_some_and(|c| c.is_ascii_digit() || c == '-' || c == '+')
+            && let Ok(f) = tok.parse::<f64>()
+          {
+            node!(NodeKind::Float(f), end)
+          } else {
+            node!(NodeKind::Symbol(tok.into()), end)
+          }
+        }
+      }
+    }
+  }
+}
+
+fn parse_seq(source: &str, mut pos: usize, close: char) -> Result<(Vec<Node>, usize), ParseError> {
+  let mut children = Vec::new();
+  loop {
+    let after_ws = skip_whitespace(source, pos);
+    if source[after_ws..].starts_with(close) {
+      return Ok((children, after_ws + close.len_utf8()));
+    }
+    let Some((node, end)) = parse_one(source, pos)? else {
+      return Err(ParseError { message: "unexpected end of input, expected closing delimiter".into(), offset: after_ws });
+    };
+    children.push(node);
+    pos = end;
+  }
+}
+
+fn parse_string(source: &str, start: usize) -> Result<(String, usize), ParseError> {
+  let mut out = String::new();
+  let mut chars = source[start + 1..].char_indices();
+  loop {
+    let Some((i, c)) = chars.next() else {
+      return Err(ParseError { message: "unterminated string literal".into(), offset: start });
+    };
+    match c {
+      '"' => return Ok((out, start + 1 + i + 1)),
+      '\\' => {
+        let Some((_, escaped)) = chars.next() else {
+          return Err(ParseError { message: "unterminated string literal".into(), offset: start });
+        };
+        out.push(match escaped {
+          'n' => '\n',
+          't' => '\t',
+          'r' => '\r',
+          '\\' => '\\',
+          '"' => '"',
+          other => other,
+        });
+      }
+      other => out.push(other),
+    }
+  }
+}
+
+fn parse_char(source: &str, start: usize) -> Result<(char, usize), ParseError> {
+  let (tok, end) = take_token(source, start + 1);
+  let ch = match tok {
+    "newline" => '\n',
+    "space" => ' ',
+    "tab" => '\t',
+    "return" => '\r',
+    _ if tok.chars().count() == 1 => tok.chars().next().unwrap(),
+    _ => return Err(ParseError { message: "unrecognized character literal".into(), offset: start }),
+  };
+  Ok((ch, end))
+}
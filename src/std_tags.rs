@@ -0,0 +1,222 @@
+//! Built-in readers/writers for EDN's two standard tagged elements, `#inst` and `#uuid`
+//!
+//! ## Implementations
+//! -  [`install`] is called by [`Reader::new`][crate::edn::Reader::new] whenever the `std-tags`
+//!    feature is enabled (the default), registering both tags
+//! -  [`Uuid`] & [`Instant`] implement [`Debug`], [`Display`], [`Clone`], [`Eq`], [`Ord`] & [`Hash`]
+//!    so they satisfy [`DataTrait`][crate::data::DataTrait]
+
+use crate::{
+  data::Datum,
+  edn::{Edn, Reader, ReadError, TagError},
+  parse::NodeKind,
+};
+use alloc::{format, string::String};
+use core::fmt;
+
+/// Installs the `#inst` and `#uuid` readers & writers on `reader`
+pub fn install(reader: &mut Reader) {
+  reader.add_reader("uuid", |node| {
+    let position = node.span.start_position();
+    let NodeKind::String(s) = node.kind else {
+      return Err(ReadError::Tag(TagError { message: "#uuid expects a string".into(), position }));
+    };
+    let uuid = Uuid::parse(&s).map_err(|err| ReadError::Tag(TagError { message: format!("{err}"), position }))?;
+    Ok(Edn::Data(Datum::new(uuid)))
+  });
+  reader.add_writer::<Uuid>("uuid", |uuid, f| write!(f, "\"{uuid:?}\""));
+
+  reader.add_reader("inst", |node| {
+    let position = node.span.start_position();
+    let NodeKind::String(s) = node.kind else {
+      return Err(ReadError::Tag(TagError { message: "#inst expects a string".into(), position }));
+    };
+    let inst = Instant::parse(&s).map_err(|err| ReadError::Tag(TagError { message: format!("{err}"), position }))?;
+    Ok(Edn::Data(Datum::new(inst)))
+  });
+  reader.add_writer::<Instant>("inst", |inst, f| write!(f, "\"{inst:?}\""));
+}
+
+/// A 16-byte UUID, as read from a `#uuid "..."` tagged element
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Uuid([u8; 16]);
+
+/// Malformed `#uuid` source text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidUuid(String);
+
+impl fmt::Display for InvalidUuid {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "invalid uuid {:?}", self.0)
+  }
+}
+
+impl Uuid {
+  pub fn parse(s: &str) -> Result<Self, InvalidUuid> {
+    let mut bytes = [0u8; 16];
+    let mut nibbles = s.chars().filter(|c| *c != '-');
+    for byte in &mut bytes {
+      let (Some(hi), Some(lo)) = (nibbles.next(), nibbles.next()) else {
+        return Err(InvalidUuid(s.into()));
+      };
+      let (Some(hi), Some(lo)) = (hi.to_digit(16), lo.to_digit(16)) else {
+        return Err(InvalidUuid(s.into()));
+      };
+      *byte = ((hi << 4) | lo) as u8;
+    }
+    if nibbles.next().is_some() {
+      return Err(InvalidUuid(s.into()));
+    }
+    Ok(Self(bytes))
+  }
+
+  pub fn as_bytes(&self) -> &[u8; 16] {
+    &self.0
+  }
+}
+
+impl fmt::Display for Uuid {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt::Debug::fmt(self, f)
+  }
+}
+
+impl fmt::Debug for Uuid {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let [a0, a1, a2, a3, b0, b1, c0, c1, d0, d1, e0, e1, e2, e3, e4, e5] = self.0;
+    write!(
+      f,
+      "{a0:02x}{a1:02x}{a2:02x}{a3:02x}-{b0:02x}{b1:02x}-{c0:02x}{c1:02x}-{d0:02x}{d1:02x}-{e0:02x}{e1:02x}{e2:02x}{e3:02x}{e4:02x}{e5:02x}"
+    )
+  }
+}
+
+/// A point in time, normalized from a `#inst "..."` RFC-3339 string to milliseconds since the
+/// Unix epoch
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Instant {
+  millis_since_epoch: i64,
+}
+
+/// Malformed `#inst` source text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidInstant(String);
+
+impl fmt::Display for InvalidInstant {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "invalid RFC-3339 instant {:?}", self.0)
+  }
+}
+
+impl Instant {
+  pub fn parse(s: &str) -> Result<Self, InvalidInstant> {
+    let err = || InvalidInstant(s.into());
+
+    let (s, offset_minutes) = match s.strip_suffix(['Z', 'z']) {
+      Some(s) => (s, 0),
+      None => {
+        let (s, offset) = s.split_at_checked(s.len().checked_sub(6).ok_or_else(err)?).ok_or_else(err)?;
+        let sign = if offset.starts_with('+') { 1 } else if offset.starts_with('-') { -1 } else { return Err(err()) };
+        let (h, m) = offset[1..].split_once(':').ok_or_else(err)?;
+        let (h, m): (i64, i64) = (h.parse().map_err(|_| err())?, m.parse().map_err(|_| err())?);
+        if !(0..24).contains(&h) || !(0..60).contains(&m) {
+          return Err(err());
+        }
+        (s, sign * (h * 60 + m))
+      }
+    };
+    let (date, time) = s.split_once(['T', 't']).ok_or_else(err)?;
+
+    let mut date = date.split('-');
+    let year: i64 = date.next().ok_or_else(err)?.parse().map_err(|_| err())?;
+    let month: u32 = date.next().ok_or_else(err)?.parse().map_err(|_| err())?;
+    let day: u32 = date.next().ok_or_else(err)?.parse().map_err(|_| err())?;
+    if date.next().is_some() || !(1..=12).contains(&month) || !(1..=days_in_month(year, month)).contains(&day) {
+      return Err(err());
+    }
+
+    let (time, millis) = match time.split_once('.') {
+      Some((time, fraction)) => {
+        let fraction = format!("{fraction:0<3}");
+        (time, fraction.get(..3).ok_or_else(err)?.parse::<i64>().map_err(|_| err())?)
+      }
+      None => (time, 0),
+    };
+    let mut time = time.split(':');
+    let hour: i64 = time.next().ok_or_else(err)?.parse().map_err(|_| err())?;
+    let minute: i64 = time.next().ok_or_else(err)?.parse().map_err(|_| err())?;
+    let second: i64 = time.next().ok_or_else(err)?.parse().map_err(|_| err())?;
+    if time.next().is_some() || !(0..24).contains(&hour) || !(0..60).contains(&minute) || !(0..61).contains(&second) {
+      return Err(err());
+    }
+
+    let days = days_from_civil(year, month, day);
+    let seconds = days * 86400 + hour * 3600 + minute * 60 + second - offset_minutes * 60;
+    Ok(Self { millis_since_epoch: seconds * 1000 + millis })
+  }
+
+  pub fn millis_since_epoch(&self) -> i64 {
+    self.millis_since_epoch
+  }
+}
+
+/// Number of days in the given proleptic-Gregorian year & month, accounting for leap years
+fn days_in_month(year: i64, month: u32) -> u32 {
+  match month {
+    1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+    4 | 6 | 9 | 11 => 30,
+    _ => {
+      if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) {
+        29
+      } else {
+        28
+      }
+    }
+  }
+}
+
+/// Days since the Unix epoch (1970-01-01) for a given proleptic-Gregorian date, per Howard
+/// Hinnant's `days_from_civil` (<https://howardhinnant.github.io/date_algorithms.html>)
+fn days_from_civil(y: i64, m: u32, d: u32) -> i64 {
+  let y = if m <= 2 { y - 1 } else { y };
+  let era = if y >= 0 { y } else { y - 399 } / 400;
+  let yoe = y - era * 400;
+  let mp = (i64::from(m) + 9) % 12;
+  let doy = (153 * mp + 2) / 5 + i64::from(d) - 1;
+  let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+  era * 146097 + doe - 719468
+}
+
+/// Inverse of [`days_from_civil`], used by [`Instant`]'s `Display` impl
+fn civil_from_days(z: i64) -> (i64, u32, u32) {
+  let z = z + 719468;
+  let era = if z >= 0 { z } else { z - 146096 } / 146097;
+  let doe = z - era * 146097;
+  let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+  let y = yoe + era * 400;
+  let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+  let mp = (5 * doy + 2) / 153;
+  let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+  let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+  (if m <= 2 { y + 1 } else { y }, m, d)
+}
+
+impl fmt::Display for Instant {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt::Debug::fmt(self, f)
+  }
+}
+
+impl fmt::Debug for Instant {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let total_millis = self.millis_since_epoch;
+    let days = total_millis.div_euclid(86_400_000);
+    let ms_of_day = total_millis.rem_euclid(86_400_000);
+    let (year, month, day) = civil_from_days(days);
+    let hour = ms_of_day / 3_600_000;
+    let minute = (ms_of_day / 60_000) % 60;
+    let second = (ms_of_day / 1_000) % 60;
+    let millis = ms_of_day % 1_000;
+    write!(f, "{year:04}-{month:02}-{day:02}T{hour:02}:{minute:02}:{second:02}.{millis:03}Z")
+  }
+}